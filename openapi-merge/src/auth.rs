@@ -0,0 +1,191 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Per-source credentials, read from a source dict's optional `auth` sub-dict.
+pub enum AuthConfig {
+    Bearer(String),
+    Basic { username: String, password: String },
+    ApiKey { header: String, value: String },
+}
+
+pub fn parse_auth(dict: Option<&PyDict>) -> PyResult<Option<AuthConfig>> {
+    let dict = match dict {
+        Some(d) => d,
+        None => return Ok(None),
+    };
+
+    let kind: String = dict
+        .get_item("type")
+        .and_then(|v| v.extract().ok())
+        .unwrap_or_default();
+
+    match kind.as_str() {
+        "bearer" => {
+            let token: String = dict
+                .get_item("token")
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Bearer auth config requires a 'token'",
+                    )
+                })?
+                .extract()?;
+            Ok(Some(AuthConfig::Bearer(token)))
+        }
+        "basic" => {
+            let username: String = dict
+                .get_item("username")
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Basic auth config requires 'username'",
+                    )
+                })?
+                .extract()?;
+            let password: String = dict
+                .get_item("password")
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Basic auth config requires 'password'",
+                    )
+                })?
+                .extract()?;
+            Ok(Some(AuthConfig::Basic { username, password }))
+        }
+        "api_key" => {
+            let header: String = dict
+                .get_item("header")
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "API key auth config requires 'header'",
+                    )
+                })?
+                .extract()?;
+            let value: String = dict
+                .get_item("value")
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "API key auth config requires 'value'",
+                    )
+                })?
+                .extract()?;
+            Ok(Some(AuthConfig::ApiKey { header, value }))
+        }
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unknown auth type: {}",
+            other
+        ))),
+    }
+}
+
+pub fn apply_auth(
+    builder: reqwest::RequestBuilder,
+    auth: &Option<AuthConfig>,
+) -> reqwest::RequestBuilder {
+    match auth {
+        Some(AuthConfig::Bearer(token)) => builder.bearer_auth(token),
+        Some(AuthConfig::Basic { username, password }) => {
+            builder.basic_auth(username, Some(password))
+        }
+        Some(AuthConfig::ApiKey { header, value }) => builder.header(header.as_str(), value),
+        None => builder,
+    }
+}
+
+/// Ed25519 integrity config, read from a source dict's optional `signature` sub-dict.
+pub struct SignatureConfig {
+    pub public_key_b64: String,
+    pub header: String,
+}
+
+pub fn parse_signature(dict: Option<&PyDict>) -> PyResult<Option<SignatureConfig>> {
+    let dict = match dict {
+        Some(d) => d,
+        None => return Ok(None),
+    };
+
+    let public_key_b64: String = dict
+        .get_item("public_key")
+        .ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Signature config requires a 'public_key'",
+            )
+        })?
+        .extract()?;
+
+    let header: String = dict
+        .get_item("header")
+        .and_then(|v| v.extract().ok())
+        .unwrap_or_else(|| "X-Schema-Signature".to_string());
+
+    Ok(Some(SignatureConfig {
+        public_key_b64,
+        header,
+    }))
+}
+
+/// Verifies `body` against a base64-encoded `signature_b64` using the configured Ed25519 key.
+pub fn verify_signature(config: &SignatureConfig, body: &[u8], signature_b64: &str) -> Result<(), String> {
+    let key_bytes = BASE64
+        .decode(&config.public_key_b64)
+        .map_err(|e| format!("Invalid base64 public key: {}", e))?;
+    let key_array: [u8; 32] = key_bytes
+        .try_into()
+        .map_err(|_| "Public key must be 32 bytes".to_string())?;
+    let verifying_key =
+        VerifyingKey::from_bytes(&key_array).map_err(|e| format!("Invalid public key: {}", e))?;
+
+    let sig_bytes = BASE64
+        .decode(signature_b64)
+        .map_err(|e| format!("Invalid base64 signature: {}", e))?;
+    let sig_array: [u8; 64] = sig_bytes
+        .try_into()
+        .map_err(|_| "Signature must be 64 bytes".to_string())?;
+    let signature = Signature::from_bytes(&sig_array);
+
+    verifying_key
+        .verify(body, &signature)
+        .map_err(|e| format!("Signature verification failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7u8; 32])
+    }
+
+    #[test]
+    fn verify_signature_accepts_matching_signature() {
+        let signing_key = signing_key();
+        let body = b"{\"openapi\":\"3.1.0\"}";
+        let signature = signing_key.sign(body);
+
+        let config = SignatureConfig {
+            public_key_b64: BASE64.encode(signing_key.verifying_key().to_bytes()),
+            header: "X-Schema-Signature".to_string(),
+        };
+
+        let result = verify_signature(&config, body, &BASE64.encode(signature.to_bytes()));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn verify_signature_rejects_tampered_body() {
+        let signing_key = signing_key();
+        let body = b"{\"openapi\":\"3.1.0\"}";
+        let signature = signing_key.sign(body);
+
+        let config = SignatureConfig {
+            public_key_b64: BASE64.encode(signing_key.verifying_key().to_bytes()),
+            header: "X-Schema-Signature".to_string(),
+        };
+
+        let tampered_body = b"{\"openapi\":\"3.1.1\"}";
+        let result = verify_signature(&config, tampered_body, &BASE64.encode(signature.to_bytes()));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use atom_syndication::{Entry, EntryBuilder, Feed, FeedBuilder, Person, Text};
+use chrono::{DateTime, FixedOffset, Utc};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::{json, Value};
+
+use crate::tags::split_service_tag;
+
+const METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+fn operation_service(op: &Value) -> String {
+    op.get("tags")
+        .and_then(|t| t.as_array())
+        .and_then(|a| a.first())
+        .and_then(|t| t.as_str())
+        .map(|tag| split_service_tag(tag).0)
+        .unwrap_or_else(|| "default".to_string())
+}
+
+fn collect_operations(schema: &Value) -> BTreeMap<(String, String), Value> {
+    let mut ops = BTreeMap::new();
+    if let Some(paths) = schema.get("paths").and_then(|p| p.as_object()) {
+        for (path, methods) in paths {
+            let methods_obj = match methods.as_object() {
+                Some(m) => m,
+                None => continue,
+            };
+            for method in METHODS {
+                if let Some(op) = methods_obj.get(*method) {
+                    ops.insert((method.to_string(), path.clone()), op.clone());
+                }
+            }
+        }
+    }
+    ops
+}
+
+fn param_names(op: &Value) -> BTreeSet<String> {
+    op.get("parameters")
+        .and_then(|p| p.as_array())
+        .map(|params| {
+            params
+                .iter()
+                .filter_map(|p| p.get("name").and_then(|n| n.as_str()))
+                .map(|s| s.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn response_codes(op: &Value) -> BTreeSet<String> {
+    op.get("responses")
+        .and_then(|r| r.as_object())
+        .map(|r| r.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[pyfunction]
+pub fn diff_schemas_rust(old_schema: &str, new_schema: &str) -> PyResult<String> {
+    let old: Value = serde_json::from_str(old_schema).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse old schema: {}", e))
+    })?;
+    let new: Value = serde_json::from_str(new_schema).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse new schema: {}", e))
+    })?;
+
+    let old_ops = collect_operations(&old);
+    let new_ops = collect_operations(&new);
+
+    let mut diffs = Vec::new();
+
+    for (key, op) in &new_ops {
+        if !old_ops.contains_key(key) {
+            diffs.push(json!({
+                "change": "added",
+                "service": operation_service(op),
+                "method": key.0,
+                "path": key.1,
+                "summary": op.get("summary").and_then(|s| s.as_str()).unwrap_or("")
+            }));
+        }
+    }
+
+    for (key, op) in &old_ops {
+        if !new_ops.contains_key(key) {
+            diffs.push(json!({
+                "change": "removed",
+                "service": operation_service(op),
+                "method": key.0,
+                "path": key.1,
+                "summary": op.get("summary").and_then(|s| s.as_str()).unwrap_or("")
+            }));
+        }
+    }
+
+    for (key, new_op) in &new_ops {
+        let old_op = match old_ops.get(key) {
+            Some(op) => op,
+            None => continue,
+        };
+
+        let old_params = param_names(old_op);
+        let new_params = param_names(new_op);
+        let old_responses = response_codes(old_op);
+        let new_responses = response_codes(new_op);
+
+        let params_added: Vec<&String> = new_params.difference(&old_params).collect();
+        let params_removed: Vec<&String> = old_params.difference(&new_params).collect();
+        let responses_added: Vec<&String> = new_responses.difference(&old_responses).collect();
+        let responses_removed: Vec<&String> = old_responses.difference(&new_responses).collect();
+
+        if !params_added.is_empty()
+            || !params_removed.is_empty()
+            || !responses_added.is_empty()
+            || !responses_removed.is_empty()
+        {
+            diffs.push(json!({
+                "change": "modified",
+                "service": operation_service(new_op),
+                "method": key.0,
+                "path": key.1,
+                "summary": new_op.get("summary").and_then(|s| s.as_str()).unwrap_or(""),
+                "parameters_added": params_added,
+                "parameters_removed": params_removed,
+                "responses_added": responses_added,
+                "responses_removed": responses_removed
+            }));
+        }
+    }
+
+    Ok(serde_json::to_string(&diffs).unwrap())
+}
+
+fn parse_timestamp(value: Option<&str>) -> PyResult<DateTime<FixedOffset>> {
+    match value {
+        Some(ts) => DateTime::parse_from_rfc3339(ts).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid RFC-3339 timestamp: {}",
+                e
+            ))
+        }),
+        None => Ok(Utc::now().into()),
+    }
+}
+
+#[pyfunction]
+pub fn render_change_feed_rust(diffs_json: &str, feed_metadata: &PyDict) -> PyResult<String> {
+    let diffs: Vec<Value> = serde_json::from_str(diffs_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse diffs: {}", e))
+    })?;
+
+    let feed_id: String = feed_metadata
+        .get_item("id")
+        .and_then(|v| v.extract().ok())
+        .unwrap_or_else(|| "urn:spec-mesh:change-feed".to_string());
+    let feed_title: String = feed_metadata
+        .get_item("title")
+        .and_then(|v| v.extract().ok())
+        .unwrap_or_else(|| "Spec Mesh Changes".to_string());
+    let author_name: String = feed_metadata
+        .get_item("author")
+        .and_then(|v| v.extract().ok())
+        .unwrap_or_else(|| "spec-mesh".to_string());
+    let generated_at: Option<String> = feed_metadata
+        .get_item("generated_at")
+        .and_then(|v| v.extract().ok());
+
+    let updated = parse_timestamp(generated_at.as_deref())?;
+
+    let mut entries = Vec::new();
+    for diff in &diffs {
+        let service = diff.get("service").and_then(|s| s.as_str()).unwrap_or("default");
+        let method = diff.get("method").and_then(|s| s.as_str()).unwrap_or("");
+        let path = diff.get("path").and_then(|s| s.as_str()).unwrap_or("");
+        let change = diff.get("change").and_then(|s| s.as_str()).unwrap_or("modified");
+        let summary = diff.get("summary").and_then(|s| s.as_str()).unwrap_or("");
+
+        let entry: Entry = EntryBuilder::default()
+            .id(format!("urn:spec-mesh:{}|{}|{}", service, method, path))
+            .title(Text::plain(format!(
+                "[{}] {} {} {}",
+                change,
+                method.to_uppercase(),
+                path,
+                summary
+            )))
+            .updated(updated)
+            .summary(Some(Text::plain(serde_json::to_string(diff).unwrap())))
+            .build();
+        entries.push(entry);
+    }
+
+    let feed: Feed = FeedBuilder::default()
+        .id(feed_id)
+        .title(Text::plain(feed_title))
+        .updated(updated)
+        .authors(vec![Person {
+            name: author_name,
+            ..Default::default()
+        }])
+        .entries(entries)
+        .build();
+
+    Ok(feed.to_string())
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use pyo3::prelude::*;
+use serde_json::Value;
+
+const METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+struct Operation<'a> {
+    path: &'a str,
+    method: &'a str,
+    op: &'a Value,
+}
+
+#[pyfunction]
+pub fn render_schema_docs_rust(merged_schema_json: &str, format: &str) -> PyResult<String> {
+    let schema: Value = serde_json::from_str(merged_schema_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Failed to parse merged schema: {}",
+            e
+        ))
+    })?;
+
+    let grouped = group_by_tag(&schema);
+    let markdown = render_markdown(&schema, &grouped);
+
+    match format {
+        "markdown" | "md" => Ok(markdown),
+        "html" => Ok(markdown_to_html(&markdown)),
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unsupported doc format: {}",
+            other
+        ))),
+    }
+}
+
+/// Groups every operation by its first tag, falling back to "default" when untagged.
+fn group_by_tag(schema: &Value) -> BTreeMap<String, Vec<Operation>> {
+    let mut grouped: BTreeMap<String, Vec<Operation>> = BTreeMap::new();
+
+    if let Some(paths) = schema.get("paths").and_then(|p| p.as_object()) {
+        for (path, methods) in paths {
+            let methods_obj = match methods.as_object() {
+                Some(m) => m,
+                None => continue,
+            };
+            for method in METHODS {
+                let op = match methods_obj.get(*method) {
+                    Some(op) => op,
+                    None => continue,
+                };
+                let tag = op
+                    .get("tags")
+                    .and_then(|t| t.as_array())
+                    .and_then(|a| a.first())
+                    .and_then(|t| t.as_str())
+                    .unwrap_or("default")
+                    .to_string();
+
+                grouped.entry(tag).or_default().push(Operation { path, method, op });
+            }
+        }
+    }
+
+    grouped
+}
+
+fn render_markdown(schema: &Value, grouped: &BTreeMap<String, Vec<Operation>>) -> String {
+    let mut out = String::new();
+
+    let title = schema
+        .get("info")
+        .and_then(|i| i.get("title"))
+        .and_then(|t| t.as_str())
+        .unwrap_or("API Reference");
+    out.push_str(&format!("# {}\n\n", title));
+
+    for (tag, operations) in grouped {
+        out.push_str(&format!("## {}\n\n", tag));
+
+        for operation in operations {
+            render_operation(schema, operation, &mut out);
+        }
+    }
+
+    out
+}
+
+fn render_operation(schema: &Value, operation: &Operation, out: &mut String) {
+    out.push_str(&format!(
+        "### {} {}\n\n",
+        operation.method.to_uppercase(),
+        operation.path
+    ));
+
+    if let Some(summary) = operation.op.get("summary").and_then(|s| s.as_str()) {
+        out.push_str(&format!("{}\n\n", summary));
+    }
+    if let Some(description) = operation.op.get("description").and_then(|s| s.as_str()) {
+        out.push_str(&format!("{}\n\n", description));
+    }
+
+    if let Some(parameters) = operation.op.get("parameters").and_then(|p| p.as_array()) {
+        if !parameters.is_empty() {
+            out.push_str("| Name | In | Required | Type |\n");
+            out.push_str("| --- | --- | --- | --- |\n");
+            for param in parameters {
+                let name = param.get("name").and_then(|n| n.as_str()).unwrap_or("");
+                let location = param.get("in").and_then(|n| n.as_str()).unwrap_or("");
+                let required = param
+                    .get("required")
+                    .and_then(|r| r.as_bool())
+                    .unwrap_or(false);
+                let type_name = param
+                    .get("schema")
+                    .and_then(|s| s.get("type"))
+                    .and_then(|t| t.as_str())
+                    .unwrap_or("any");
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    name, location, required, type_name
+                ));
+            }
+            out.push('\n');
+        }
+    }
+
+    if let Some(content) = operation
+        .op
+        .get("requestBody")
+        .and_then(|b| b.get("content"))
+        .and_then(|c| c.as_object())
+    {
+        out.push_str("**Request body:**\n\n");
+        for media_type in content.keys() {
+            out.push_str(&format!("- `{}`\n", media_type));
+        }
+        out.push('\n');
+    }
+
+    if let Some(responses) = operation.op.get("responses").and_then(|r| r.as_object()) {
+        out.push_str("**Responses:**\n\n");
+        out.push_str("| Status | Schema |\n");
+        out.push_str("| --- | --- |\n");
+        for (status, response) in responses {
+            let schema_name = response
+                .get("content")
+                .and_then(|c| c.as_object())
+                .and_then(|c| c.values().next())
+                .and_then(|media| media.get("schema"))
+                .and_then(|s| s.get("$ref"))
+                .and_then(|r| r.as_str())
+                .map(|r| r.rsplit('/').next().unwrap_or(r).to_string())
+                .unwrap_or_else(|| "-".to_string());
+            out.push_str(&format!("| {} | {} |\n", status, schema_name));
+
+            if schema_name != "-" {
+                if let Some(fields) = resolve_schema_fields(schema, &schema_name) {
+                    out.push_str(&format!("\n`{}` fields:\n\n", schema_name));
+                    for field in fields {
+                        out.push_str(&format!("- `{}`\n", field));
+                    }
+                    out.push('\n');
+                }
+            }
+        }
+        out.push('\n');
+    }
+}
+
+/// Resolves `#/components/schemas/<name>` one level deep and returns its field names.
+fn resolve_schema_fields(schema: &Value, name: &str) -> Option<Vec<String>> {
+    let properties = schema
+        .get("components")?
+        .get("schemas")?
+        .get(name)?
+        .get("properties")?
+        .as_object()?;
+
+    Some(properties.keys().cloned().collect())
+}
+
+/// Minimal Markdown -> HTML pass sufficient for the structure `render_markdown` emits.
+fn markdown_to_html(markdown: &str) -> String {
+    let mut html = String::from("<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"></head><body>\n");
+    let mut in_table = false;
+    let mut in_list = false;
+
+    for line in markdown.lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            close_list(&mut html, &mut in_list);
+            html.push_str(&format!("<h3>{}</h3>\n", escape_html(heading)));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            close_list(&mut html, &mut in_list);
+            html.push_str(&format!("<h2>{}</h2>\n", escape_html(heading)));
+        } else if let Some(heading) = line.strip_prefix("# ") {
+            close_list(&mut html, &mut in_list);
+            html.push_str(&format!("<h1>{}</h1>\n", escape_html(heading)));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            if !in_list {
+                html.push_str("<ul>\n");
+                in_list = true;
+            }
+            html.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+        } else if line.starts_with('|') {
+            close_list(&mut html, &mut in_list);
+            if line.replace('-', "").replace('|', "").trim().is_empty() {
+                continue;
+            }
+            let cells: Vec<&str> = line.trim_matches('|').split('|').map(|c| c.trim()).collect();
+            if !in_table {
+                html.push_str("<table>\n");
+                in_table = true;
+            }
+            html.push_str("<tr>");
+            for cell in cells {
+                html.push_str(&format!("<td>{}</td>", escape_html(cell)));
+            }
+            html.push_str("</tr>\n");
+        } else {
+            if in_table {
+                html.push_str("</table>\n");
+                in_table = false;
+            }
+            close_list(&mut html, &mut in_list);
+            if !line.trim().is_empty() {
+                html.push_str(&format!("<p>{}</p>\n", escape_html(line)));
+            }
+        }
+    }
+
+    if in_table {
+        html.push_str("</table>\n");
+    }
+    close_list(&mut html, &mut in_list);
+    html.push_str("</body></html>\n");
+    html
+}
+
+fn close_list(html: &mut String, in_list: &mut bool) {
+    if *in_list {
+        html.push_str("</ul>\n");
+        *in_list = false;
+    }
+}
+
+/// Escapes the handful of characters that matter inside HTML text content.
+fn escape_html(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
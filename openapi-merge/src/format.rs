@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::fsutil;
+
+/// Parses `input` as JSON5 first (a superset of JSON that also tolerates comments, trailing
+/// commas, and unquoted keys) and falls back to YAML. Covers every accepted input shape for
+/// the `*_rust` entry points without each of them re-implementing the fallback chain.
+pub fn parse_flexible(input: &str) -> Result<Value, String> {
+    json5::from_str(input)
+        .or_else(|_| serde_yaml::from_str(input))
+        .map_err(|e| format!("Failed to parse input as JSON5 or YAML: {}", e))
+}
+
+/// Serializes `value` per `pretty`, and when `output_path` is set, also writes it there after
+/// validating the extension is `.json`, `.yaml`, or `.yml` (the target format follows the
+/// extension rather than `pretty`, since YAML has no meaningful compact mode).
+pub fn finalize_output(
+    value: &Value,
+    pretty: bool,
+    output_path: Option<&str>,
+) -> Result<String, String> {
+    let extension = output_path.and_then(|p| p.rsplit('.').next()).map(|e| e.to_lowercase());
+
+    if let Some(ext) = &extension {
+        if ext != "json" && ext != "yaml" && ext != "yml" {
+            return Err(format!(
+                "output_path must end with .json, .yaml, or .yml (got .{})",
+                ext
+            ));
+        }
+    }
+
+    let content = serialize(value, pretty, extension.as_deref())?;
+
+    if let Some(path) = output_path {
+        fsutil::atomic_write_locked(Path::new(path), content.as_bytes())
+            .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+
+    Ok(content)
+}
+
+fn serialize(value: &Value, pretty: bool, extension: Option<&str>) -> Result<String, String> {
+    match extension {
+        Some("yaml") | Some("yml") => serde_yaml::to_string(value).map_err(|e| e.to_string()),
+        _ => {
+            if pretty {
+                serde_json::to_string_pretty(value).map_err(|e| e.to_string())
+            } else {
+                serde_json::to_string(value).map_err(|e| e.to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use fs2::FileExt;
+
+/// Holds an exclusive advisory lock on a `.lock` sentinel inside `dir`, releasing it on drop.
+/// Lets a caller that needs several writes to act as one transaction take the lock once for
+/// the whole sequence, rather than `atomic_write_locked` re-acquiring it per call.
+pub struct DirLock {
+    file: File,
+}
+
+impl Drop for DirLock {
+    fn drop(&mut self) {
+        let _ = fs2::FileExt::unlock(&self.file);
+    }
+}
+
+pub fn lock_dir(dir: &Path) -> io::Result<DirLock> {
+    fs::create_dir_all(dir)?;
+    let lock_path = dir.join(".lock");
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(&lock_path)?;
+    file.lock_exclusive()?;
+    Ok(DirLock { file })
+}
+
+/// Writes `content` to `path` via a temp file plus fsync plus rename, with no locking of its
+/// own; use this under an already-held `DirLock`, or use `atomic_write_locked` otherwise.
+pub fn write_atomic(path: &Path, content: &[u8]) -> io::Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir)?;
+
+    let tmp_path = tmp_path_for(path);
+    {
+        let mut tmp_file = File::create(&tmp_path)?;
+        tmp_file.write_all(content)?;
+        tmp_file.sync_all()?;
+    }
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Writes `content` to `path` safely under concurrent invocations via a `DirLock` plus
+/// `write_atomic`.
+pub fn atomic_write_locked(path: &Path, content: &[u8]) -> io::Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let _lock = lock_dir(dir)?;
+    write_atomic(path, content)
+}
+
+fn tmp_path_for(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "output".to_string());
+    path.with_file_name(format!(".{}.tmp", file_name))
+}
@@ -7,6 +7,24 @@ use serde_json::{json, Map, Value};
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
+mod auth;
+mod diff;
+mod docs;
+mod format;
+mod fsutil;
+mod portal;
+mod postman;
+mod refs;
+mod tags;
+mod validation;
+mod versiondag;
+use diff::{diff_schemas_rust, render_change_feed_rust};
+use docs::render_schema_docs_rust;
+use portal::render_portal_rust;
+use postman::postman_to_openapi_rust;
+use validation::validate_schema_rust;
+use versiondag::{diff_versions_rust, get_version_rust, history_rust, rollback_rust};
+
 // === Типы ===
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Source {
@@ -83,10 +101,11 @@ fn prepare_server_for_schema_rust(
     schema_json: &str,
     url: &str,
     source_name: Option<&str>,
+    pretty: bool,
+    output_path: Option<&str>,
 ) -> PyResult<String> {
-    let mut schema: Value = serde_json::from_str(schema_json).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse schema: {}", e))
-    })?;
+    let mut schema: Value = format::parse_flexible(schema_json)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
 
     let proxy_enabled = std::env::var("PROXY_ENABLED").unwrap_or_default() == "true"
         || std::env::var("PROXY").unwrap_or_default() == "true";
@@ -127,19 +146,19 @@ fn prepare_server_for_schema_rust(
         }
     }
 
-    serde_json::to_string(&schema).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "Failed to serialize schema: {}",
-            e
-        ))
-    })
+    format::finalize_output(&schema, pretty, output_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
 }
 
 #[pyfunction]
-fn prepare_grouping_rust(schema_json: &str, name: &str) -> PyResult<String> {
-    let mut schema: Value = serde_json::from_str(schema_json).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse schema: {}", e))
-    })?;
+fn prepare_grouping_rust(
+    schema_json: &str,
+    name: &str,
+    pretty: bool,
+    output_path: Option<&str>,
+) -> PyResult<String> {
+    let mut schema: Value = format::parse_flexible(schema_json)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
 
     // Обработка глобальных тегов
     if let Some(tags) = schema.get_mut("tags").and_then(|v| v.as_array_mut()) {
@@ -174,12 +193,8 @@ fn prepare_grouping_rust(schema_json: &str, name: &str) -> PyResult<String> {
         }
     }
 
-    serde_json::to_string(&schema).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "Failed to serialize schema: {}",
-            e
-        ))
-    })
+    format::finalize_output(&schema, pretty, output_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
 }
 
 #[pyfunction]
@@ -188,10 +203,11 @@ fn update_schema_metadata_rust(
     title: &str,
     description: &str,
     version: &str,
+    pretty: bool,
+    output_path: Option<&str>,
 ) -> PyResult<String> {
-    let mut schema: Value = serde_json::from_str(schema_json).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse schema: {}", e))
-    })?;
+    let mut schema: Value = format::parse_flexible(schema_json)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
 
     // Обновляем метаданные
     if let Some(info) = schema.get_mut("info").and_then(|i| i.as_object_mut()) {
@@ -208,12 +224,8 @@ fn update_schema_metadata_rust(
 
     schema["openapi"] = json!("3.1.0");
 
-    serde_json::to_string(&schema).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "Failed to serialize schema: {}",
-            e
-        ))
-    })
+    format::finalize_output(&schema, pretty, output_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
 }
 
 #[pyfunction]
@@ -255,7 +267,11 @@ fn process_sources_rust(sources: &PyList, enabled: bool) -> PyResult<Vec<PyObjec
                 result.set_item("enabled", enabled_flag)?;
 
                 // Получаем схему
-                let schema = get_schema_sync(&schema_url)?;
+                let auth_config = dict.get_item("auth").and_then(|v| v.downcast::<PyDict>().ok());
+                let signature_config = dict
+                    .get_item("signature")
+                    .and_then(|v| v.downcast::<PyDict>().ok());
+                let schema = get_schema_sync(&schema_url, auth_config, signature_config)?;
                 result.set_item("schema_data", schema)?;
 
                 results.push(result.into_py(py));
@@ -268,14 +284,23 @@ fn process_sources_rust(sources: &PyList, enabled: bool) -> PyResult<Vec<PyObjec
 // === Основные функции ===
 
 #[pyfunction]
-fn get_schema_sync(url: &str) -> PyResult<PyObject> {
+fn get_schema_sync(
+    url: &str,
+    auth_config: Option<&PyDict>,
+    signature_config: Option<&PyDict>,
+) -> PyResult<PyObject> {
+    let auth = auth::parse_auth(auth_config)?;
+    let signature = auth::parse_signature(signature_config)?;
+
     let rt = tokio::runtime::Runtime::new().unwrap();
     let result = rt.block_on(async {
         let client = reqwest::Client::builder()
             .timeout(std::time::Duration::from_secs(5))
             .build()
             .map_err(|e| e.to_string())?;
-        let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+
+        let request = auth::apply_auth(client.get(url), &auth);
+        let response = request.send().await.map_err(|e| e.to_string())?;
         let content_type = response
             .headers()
             .get("content-type")
@@ -283,7 +308,35 @@ fn get_schema_sync(url: &str) -> PyResult<PyObject> {
             .unwrap_or("")
             .to_lowercase();
 
-        let text = response.text().await.map_err(|e| e.to_string())?;
+        let header_signature = signature
+            .as_ref()
+            .and_then(|sig| response.headers().get(sig.header.as_str()))
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+
+        if let Some(sig_config) = &signature {
+            let signature_b64 = match header_signature {
+                Some(s) => s,
+                None => {
+                    let sidecar_url = format!("{}.sig", url);
+                    client
+                        .get(&sidecar_url)
+                        .send()
+                        .await
+                        .map_err(|e| e.to_string())?
+                        .text()
+                        .await
+                        .map_err(|e| e.to_string())?
+                        .trim()
+                        .to_string()
+                }
+            };
+            auth::verify_signature(sig_config, &bytes, &signature_b64)?;
+        }
+
+        let text = String::from_utf8_lossy(&bytes).to_string();
 
         let value = if content_type.contains("vnd.oai.openapi") || content_type.contains("json") {
             serde_json::from_str(&text)
@@ -351,7 +404,11 @@ fn get_schemas_sync(sources: &PyList, enabled: bool) -> PyResult<Vec<PyObject>>
                 result.set_item("enabled", enabled_flag)?;
 
                 // Получаем схему
-                let schema = get_schema_sync(&schema_url)?;
+                let auth_config = dict.get_item("auth").and_then(|v| v.downcast::<PyDict>().ok());
+                let signature_config = dict
+                    .get_item("signature")
+                    .and_then(|v| v.downcast::<PyDict>().ok());
+                let schema = get_schema_sync(&schema_url, auth_config, signature_config)?;
                 result.set_item("schema_data", schema)?;
 
                 results.push(result.into_py(py));
@@ -362,7 +419,13 @@ fn get_schemas_sync(sources: &PyList, enabled: bool) -> PyResult<Vec<PyObject>>
 }
 
 #[pyfunction]
-fn merge_schemas_sync(schemas: &PyList, grouping: bool) -> PyResult<PyObject> {
+fn merge_schemas_sync(
+    schemas: &PyList,
+    grouping: bool,
+    pretty: bool,
+    output_path: Option<&str>,
+    version_store_dir: Option<&str>,
+) -> PyResult<PyObject> {
     if schemas.len() == 0 {
         return Python::with_gil(|py| {
             let empty_dict = PyDict::new(py);
@@ -374,6 +437,7 @@ fn merge_schemas_sync(schemas: &PyList, grouping: bool) -> PyResult<PyObject> {
     let mut merged_schemas: HashMap<String, Value> = HashMap::new();
     let mut merged_components: HashMap<String, HashMap<String, Value>> = HashMap::new();
     let mut all_tags: Vec<Value> = Vec::new();
+    let mut contributing_sources: Vec<versiondag::SourceRef> = Vec::new();
 
     // Обрабатываем каждую схему
     for schema_item in schemas.iter() {
@@ -384,6 +448,14 @@ fn merge_schemas_sync(schemas: &PyList, grouping: bool) -> PyResult<PyObject> {
         let name: String = dict.get_item("name").unwrap().extract()?;
         let schema_data = dict.get_item("schema_data").unwrap();
 
+        contributing_sources.push(versiondag::SourceRef {
+            name: name.clone(),
+            url: dict
+                .get_item("url")
+                .and_then(|v| v.extract().ok())
+                .unwrap_or_default(),
+        });
+
         // Конвертируем Python объект в serde_json::Value
         let schema: Value = serde_json::from_str(&schema_data.to_string()).map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
@@ -414,6 +486,48 @@ fn merge_schemas_sync(schemas: &PyList, grouping: bool) -> PyResult<PyObject> {
             }
         }
 
+        // Предвычисляем переименования компонентов этого источника и переписываем
+        // $ref/discriminator.mapping на них до того, как компоненты попадут в merged_*.
+        let mut renames = refs::RenameMap::default();
+        if let Some(schemas_obj) = schema_with_servers
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(|s| s.as_object())
+        {
+            for schema_name in schemas_obj.keys() {
+                let key = if merged_schemas.contains_key(schema_name) {
+                    format!("{}_{}", schema_name, name)
+                } else {
+                    schema_name.clone()
+                };
+                renames.record_component("schemas", schema_name, &key);
+            }
+        }
+        if let Some(components) = schema_with_servers
+            .get("components")
+            .and_then(|c| c.as_object())
+        {
+            for (ctype, data) in components {
+                if ctype == "schemas" {
+                    continue;
+                }
+                if let Some(obj) = data.as_object() {
+                    let existing = merged_components.get(ctype);
+                    for comp_name in obj.keys() {
+                        let key = if existing.map_or(false, |m| m.contains_key(comp_name)) {
+                            format!("{}_{}", comp_name, name)
+                        } else {
+                            comp_name.clone()
+                        };
+                        renames.record_component(ctype, comp_name, &key);
+                    }
+                }
+            }
+        }
+        if !renames.is_empty() {
+            refs::rewrite_refs(&mut schema_with_servers, &renames);
+        }
+
         // Слияние путей
         if let Some(paths) = schema_with_servers.get("paths").and_then(|v| v.as_object()) {
             for (path, methods) in paths {
@@ -507,10 +621,23 @@ fn merge_schemas_sync(schemas: &PyList, grouping: bool) -> PyResult<PyObject> {
         merged["tags"] = Value::Array(all_tags);
     }
 
+    let output = format::finalize_output(&merged, pretty, output_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+
+    let version_hash = match version_store_dir {
+        Some(dir) => Some(
+            versiondag::record_version(std::path::Path::new(dir), &merged, contributing_sources)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?,
+        ),
+        None => None,
+    };
+
     Python::with_gil(|py| {
-        let json_str = serde_json::to_string(&merged).unwrap();
         let py_dict = PyDict::new(py);
-        py_dict.set_item("merged_schema", json_str).unwrap();
+        py_dict.set_item("merged_schema", output).unwrap();
+        if let Some(hash) = version_hash {
+            py_dict.set_item("version_hash", hash).unwrap();
+        }
         Ok(py_dict.into_py(py))
     })
 }
@@ -529,11 +656,12 @@ fn process_schemas_batch_rust(schemas_data: &PyList, grouping: bool) -> PyResult
         let schema_json: String = dict.get_item("schema_data").unwrap().extract()?;
 
         // Обрабатываем схему с серверами
-        let schema_with_servers = prepare_server_for_schema_rust(&schema_json, &url, Some(&name))?;
+        let schema_with_servers =
+            prepare_server_for_schema_rust(&schema_json, &url, Some(&name), false, None)?;
 
         // Если включена группировка, добавляем префикс к тегам
         let final_schema = if grouping {
-            prepare_grouping_rust(&schema_with_servers, &name)?
+            prepare_grouping_rust(&schema_with_servers, &name, false, None)?
         } else {
             schema_with_servers
         };
@@ -546,9 +674,8 @@ fn process_schemas_batch_rust(schemas_data: &PyList, grouping: bool) -> PyResult
 
 #[pyfunction]
 fn get_config_value_rust(config_json: &str, path: &str, default_value: &str) -> PyResult<String> {
-    let config: Value = serde_json::from_str(config_json).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to parse config: {}", e))
-    })?;
+    let config: Value = format::parse_flexible(config_json)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
 
     // Простое извлечение значения по пути (например, "settings/title")
     let path_parts: Vec<&str> = path.split('/').collect();
@@ -573,56 +700,6 @@ fn get_config_value_rust(config_json: &str, path: &str, default_value: &str) ->
     }
 }
 
-#[pyfunction]
-fn validate_schema_rust(schema_json: &str) -> PyResult<bool> {
-    let schema: Value = serde_json::from_str(schema_json)
-        .map_err(|_| PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid JSON format"))?;
-
-    // Базовая валидация OpenAPI схемы
-    if !schema.is_object() {
-        return Ok(false);
-    }
-
-    let obj = schema.as_object().unwrap();
-
-    // Проверяем обязательные поля
-    if !obj.contains_key("openapi") && !obj.contains_key("swagger") {
-        return Ok(false);
-    }
-
-    if !obj.contains_key("info") {
-        return Ok(false);
-    }
-
-    if !obj.contains_key("paths") {
-        return Ok(false);
-    }
-
-    // Проверяем info секцию
-    if let Some(info) = obj.get("info") {
-        if let Some(info_obj) = info.as_object() {
-            if !info_obj.contains_key("title") || !info_obj.contains_key("version") {
-                return Ok(false);
-            }
-        } else {
-            return Ok(false);
-        }
-    } else {
-        return Ok(false);
-    }
-
-    // Проверяем paths секцию
-    if let Some(paths) = obj.get("paths") {
-        if !paths.is_object() {
-            return Ok(false);
-        }
-    } else {
-        return Ok(false);
-    }
-
-    Ok(true)
-}
-
 #[pyfunction]
 fn generate_uuid_short() -> String {
     uuid::Uuid::new_v4().to_string()[..10].to_string()
@@ -736,7 +813,11 @@ fn process_sources_with_uuid_rust(sources: &PyList, enabled: bool) -> PyResult<V
                 result.set_item("enabled", enabled_flag)?;
 
                 // Получаем схему
-                let schema = get_schema_sync(&schema_url)?;
+                let auth_config = dict.get_item("auth").and_then(|v| v.downcast::<PyDict>().ok());
+                let signature_config = dict
+                    .get_item("signature")
+                    .and_then(|v| v.downcast::<PyDict>().ok());
+                let schema = get_schema_sync(&schema_url, auth_config, signature_config)?;
                 result.set_item("schema_data", schema)?;
 
                 results.push(result.into_py(py));
@@ -765,6 +846,15 @@ fn openapi_merger(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(validate_schema_rust, m)?)?;
     m.add_function(wrap_pyfunction!(generate_uuid_short, m)?)?;
     m.add_function(wrap_pyfunction!(process_sources_with_uuid_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(postman_to_openapi_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(render_schema_docs_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(render_portal_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_schemas_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(render_change_feed_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(history_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(get_version_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(rollback_rust, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_versions_rust, m)?)?;
 
     Ok(())
 }
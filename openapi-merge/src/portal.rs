@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pyo3::prelude::*;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::docs::render_schema_docs_rust;
+use crate::fsutil;
+use crate::tags::split_service_tag;
+
+const METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+const PORTAL_CSS: &str = "body{font-family:system-ui,sans-serif;margin:2rem;color:#1a1a1a}\n\
+h1,h2,h3{color:#111}\ntable{border-collapse:collapse}\ntd,th{border:1px solid #ccc;padding:.25rem .5rem}\n";
+
+const PORTAL_JS: &str = "function specMeshSearch(query){\n\
+  return (window.SPEC_MESH_SEARCH_INDEX || []).filter(function(entry){\n\
+    var q = query.toLowerCase();\n\
+    return entry.path.toLowerCase().includes(q) || entry.summary.toLowerCase().includes(q);\n\
+  });\n}\n";
+
+fn begin_marker(service: &str) -> String {
+    format!("// BEGIN {}", service)
+}
+
+fn end_marker(service: &str) -> String {
+    format!("// END {}", service)
+}
+
+fn collect_search_entries(schema: &Value) -> Vec<Value> {
+    let mut entries = Vec::new();
+
+    if let Some(paths) = schema.get("paths").and_then(|p| p.as_object()) {
+        for (path, methods) in paths {
+            let methods_obj = match methods.as_object() {
+                Some(m) => m,
+                None => continue,
+            };
+            for method in METHODS {
+                let op = match methods_obj.get(*method) {
+                    Some(op) => op,
+                    None => continue,
+                };
+                let tag = op
+                    .get("tags")
+                    .and_then(|t| t.as_array())
+                    .and_then(|a| a.first())
+                    .and_then(|t| t.as_str())
+                    .unwrap_or("default");
+                let (service, tag) = split_service_tag(tag);
+
+                entries.push(json!({
+                    "service": service,
+                    "tag": tag,
+                    "method": method.to_uppercase(),
+                    "path": path,
+                    "summary": op.get("summary").and_then(|s| s.as_str()).unwrap_or(""),
+                    "operationId": op.get("operationId").and_then(|s| s.as_str()).unwrap_or(""),
+                }));
+            }
+        }
+    }
+
+    entries
+}
+
+/// Rewrites `search-index.js`'s per-service slice between its `BEGIN`/`END` sentinels,
+/// leaving slices for every other service untouched.
+fn write_search_index(out_dir: &Path, by_service: &BTreeMap<String, Vec<Value>>) -> std::io::Result<PathBuf> {
+    let index_path = out_dir.join("search-index.js");
+
+    let existing = fs::read_to_string(&index_path).unwrap_or_default();
+    let mut blocks: BTreeMap<String, String> = BTreeMap::new();
+
+    let mut lines = existing.lines().peekable();
+    while let Some(line) = lines.next() {
+        if let Some(service) = line.strip_prefix("// BEGIN ") {
+            let mut body = String::new();
+            for inner in lines.by_ref() {
+                if inner == end_marker(service) {
+                    break;
+                }
+                body.push_str(inner);
+                body.push('\n');
+            }
+            blocks.insert(service.to_string(), body);
+        }
+    }
+
+    for (service, entries) in by_service {
+        let slice = format!(
+            "window.SPEC_MESH_SEARCH_INDEX = (window.SPEC_MESH_SEARCH_INDEX || []).concat({});\n",
+            serde_json::to_string(entries).unwrap()
+        );
+        blocks.insert(service.clone(), slice);
+    }
+
+    let mut content = String::new();
+    for (service, body) in &blocks {
+        content.push_str(&begin_marker(service));
+        content.push('\n');
+        content.push_str(body);
+        content.push_str(&end_marker(service));
+        content.push('\n');
+    }
+
+    fsutil::atomic_write_locked(&index_path, content.as_bytes())?;
+    Ok(index_path)
+}
+
+/// Writes `content` under `assets/<hash>.<ext>`, where `<hash>` is the first 16 hex digits of
+/// its SHA-256, skipping the write if that file already exists.
+fn write_deduped_asset(out_dir: &Path, content: &str, ext: &str) -> std::io::Result<PathBuf> {
+    let assets_dir = out_dir.join("assets");
+    fs::create_dir_all(&assets_dir)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    let hash = format!("{:x}", hasher.finalize());
+
+    let asset_path = assets_dir.join(format!("{}.{}", &hash[..16], ext));
+    if !asset_path.exists() {
+        fsutil::atomic_write_locked(&asset_path, content.as_bytes())?;
+    }
+    Ok(asset_path)
+}
+
+#[pyfunction]
+pub fn render_portal_rust(
+    merged_schema_json: &str,
+    out_dir: &str,
+    sources: Vec<String>,
+) -> PyResult<Vec<String>> {
+    let schema: Value = serde_json::from_str(merged_schema_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Failed to parse merged schema: {}",
+            e
+        ))
+    })?;
+
+    let out_dir = Path::new(out_dir);
+    fs::create_dir_all(out_dir)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+
+    let mut written = Vec::new();
+
+    let css_path = write_deduped_asset(out_dir, PORTAL_CSS, "css")
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    let js_path = write_deduped_asset(out_dir, PORTAL_JS, "js")
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    written.push(css_path.to_string_lossy().to_string());
+    written.push(js_path.to_string_lossy().to_string());
+
+    let entries = collect_search_entries(&schema);
+    let mut by_service: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for entry in entries {
+        let service = entry
+            .get("service")
+            .and_then(|s| s.as_str())
+            .unwrap_or("default")
+            .to_string();
+        by_service.entry(service).or_default().push(entry);
+    }
+    for source in &sources {
+        by_service.entry(source.clone()).or_default();
+    }
+
+    let index_path = write_search_index(out_dir, &by_service)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    written.push(index_path.to_string_lossy().to_string());
+
+    let docs_html = render_schema_docs_rust(merged_schema_json, "html")?;
+    let head_additions = format!(
+        "<link rel=\"stylesheet\" href=\"assets/{}\">\n\
+         <script src=\"search-index.js\"></script>\n\
+         <script src=\"assets/{}\"></script>\n</head>",
+        css_path.file_name().unwrap().to_string_lossy(),
+        js_path.file_name().unwrap().to_string_lossy(),
+    );
+    let page = docs_html.replacen("</head>", &head_additions, 1);
+
+    let index_html_path = out_dir.join("index.html");
+    fsutil::atomic_write_locked(&index_html_path, page.as_bytes())
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    written.push(index_html_path.to_string_lossy().to_string());
+
+    Ok(written)
+}
@@ -0,0 +1,469 @@
+use pyo3::prelude::*;
+use serde_json::{json, Map, Value};
+
+// === Postman -> OpenAPI ===
+
+/// Returns true if `collection` looks like a Postman Collection v2.x export.
+fn is_postman_collection(collection: &Value) -> bool {
+    let schema_matches = collection
+        .get("info")
+        .and_then(|i| i.get("schema"))
+        .and_then(|s| s.as_str())
+        .map(|s| s.contains("collection.json"))
+        .unwrap_or(false);
+
+    schema_matches && collection.get("item").and_then(|i| i.as_array()).is_some()
+}
+
+#[pyfunction]
+pub fn postman_to_openapi_rust(collection_json: &str) -> PyResult<String> {
+    let collection: Value = serde_json::from_str(collection_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Failed to parse Postman collection: {}",
+            e
+        ))
+    })?;
+
+    if !is_postman_collection(&collection) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Not a Postman Collection v2.x export (missing info.schema/item)",
+        ));
+    }
+
+    let title = collection
+        .get("info")
+        .and_then(|i| i.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("Imported Collection");
+
+    let mut paths = Map::new();
+    if let Some(items) = collection.get("item").and_then(|i| i.as_array()) {
+        walk_items(items, None, &mut paths);
+    }
+
+    Ok(serde_json::to_string(&json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": title,
+            "version": "1.0.0"
+        },
+        "paths": Value::Object(paths)
+    }))
+    .unwrap())
+}
+
+fn walk_items(items: &[Value], folder_name: Option<&str>, paths: &mut Map<String, Value>) {
+    for item in items {
+        if let Some(children) = item.get("item").and_then(|i| i.as_array()) {
+            let name = item.get("name").and_then(|n| n.as_str());
+            walk_items(children, name.or(folder_name), paths);
+            continue;
+        }
+
+        if let Some(request) = item.get("request") {
+            let tag = folder_name.unwrap_or("default");
+            let name = item.get("name").and_then(|n| n.as_str());
+            add_operation(request, name, tag, item.get("response"), paths);
+        }
+    }
+}
+
+fn add_operation(
+    request: &Value,
+    name: Option<&str>,
+    tag: &str,
+    responses: Option<&Value>,
+    paths: &mut Map<String, Value>,
+) {
+    let method = request
+        .get("method")
+        .and_then(|m| m.as_str())
+        .unwrap_or("GET")
+        .to_lowercase();
+
+    let url = match request.get("url") {
+        Some(u) => u,
+        None => return,
+    };
+
+    let (path, path_params, query_params) = resolve_url(url);
+
+    let mut operation = Map::new();
+    if let Some(n) = name {
+        operation.insert("summary".to_string(), json!(n));
+    }
+    if let Some(desc) = request.get("description").and_then(|d| d.as_str()) {
+        operation.insert("description".to_string(), json!(desc));
+    }
+    operation.insert("tags".to_string(), json!([tag]));
+
+    let mut parameters: Vec<Value> = Vec::new();
+    for param in &path_params {
+        parameters.push(json!({
+            "name": param,
+            "in": "path",
+            "required": true,
+            "schema": {"type": "string"}
+        }));
+    }
+    parameters.extend(query_params);
+    if !parameters.is_empty() {
+        operation.insert("parameters".to_string(), Value::Array(parameters));
+    }
+
+    if let Some(body) = request.get("body") {
+        if let Some(request_body) = convert_body(body) {
+            operation.insert("requestBody".to_string(), request_body);
+        }
+    }
+
+    operation.insert(
+        "responses".to_string(),
+        convert_responses(responses),
+    );
+
+    let path_entry = paths
+        .entry(path)
+        .or_insert_with(|| Value::Object(Map::new()));
+    if let Some(methods) = path_entry.as_object_mut() {
+        methods.insert(method, Value::Object(operation));
+    }
+}
+
+/// Resolves a Postman `url` into an OpenAPI path template, path params, and query params.
+fn resolve_url(url: &Value) -> (String, Vec<String>, Vec<Value>) {
+    let raw = if let Some(s) = url.as_str() {
+        s.to_string()
+    } else {
+        url.get("raw")
+            .and_then(|r| r.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| rebuild_raw_from_structured(url))
+    };
+
+    let (path, query_string) = split_query(&strip_origin(&raw));
+    let (templated, path_params) = templatize_path(&path);
+
+    let mut query_params = Vec::new();
+    if let Some(query) = url.get("query").and_then(|q| q.as_array()) {
+        for entry in query {
+            if let Some(key) = entry.get("key").and_then(|k| k.as_str()) {
+                let disabled = entry
+                    .get("disabled")
+                    .and_then(|d| d.as_bool())
+                    .unwrap_or(false);
+                if disabled {
+                    continue;
+                }
+                query_params.push(json!({
+                    "name": key,
+                    "in": "query",
+                    "required": false,
+                    "schema": {"type": "string"}
+                }));
+            }
+        }
+    } else if let Some(qs) = query_string {
+        query_params.extend(parse_query_string(&qs));
+    }
+
+    (templated, path_params, query_params)
+}
+
+/// Splits a query string off a raw path.
+fn split_query(path: &str) -> (String, Option<String>) {
+    match path.split_once('?') {
+        Some((p, q)) => (p.to_string(), Some(q.to_string())),
+        None => (path.to_string(), None),
+    }
+}
+
+/// Parses a `key=value&key2=value2` query string into OpenAPI query parameters.
+fn parse_query_string(query_string: &str) -> Vec<Value> {
+    query_string
+        .split('&')
+        .filter_map(|pair| {
+            let key = pair.split('=').next().unwrap_or("");
+            if key.is_empty() {
+                None
+            } else {
+                Some(json!({
+                    "name": key,
+                    "in": "query",
+                    "required": false,
+                    "schema": {"type": "string"}
+                }))
+            }
+        })
+        .collect()
+}
+
+fn rebuild_raw_from_structured(url: &Value) -> String {
+    let host = url
+        .get("host")
+        .and_then(|h| h.as_array())
+        .map(|parts| {
+            parts
+                .iter()
+                .filter_map(|p| p.as_str())
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .unwrap_or_default();
+
+    let path = url
+        .get("path")
+        .and_then(|p| p.as_array())
+        .map(|parts| {
+            parts
+                .iter()
+                .filter_map(|p| p.as_str())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or_default();
+
+    format!("{}/{}", host, path)
+}
+
+/// Drops the scheme/host portion of a raw Postman URL, leaving the path and any query string.
+fn strip_origin(raw: &str) -> String {
+    let without_proto = raw.splitn(2, "://").last().unwrap_or(raw);
+    match without_proto.find(['/', '?']) {
+        Some(idx) => without_proto[idx..].to_string(),
+        None => "/".to_string(),
+    }
+}
+
+/// Converts `:segment` and `{{var}}` tokens into OpenAPI `{segment}` path parameters.
+fn templatize_path(path: &str) -> (String, Vec<String>) {
+    let mut params = Vec::new();
+    let segments: Vec<String> = path
+        .split('/')
+        .map(|segment| {
+            if let Some(stripped) = segment.strip_prefix(':') {
+                params.push(stripped.to_string());
+                format!("{{{}}}", stripped)
+            } else if segment.starts_with("{{") && segment.ends_with("}}") {
+                let name = &segment[2..segment.len() - 2];
+                params.push(name.to_string());
+                format!("{{{}}}", name)
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect();
+
+    let mut templated = segments.join("/");
+    if templated.is_empty() {
+        templated = "/".to_string();
+    }
+    (templated, params)
+}
+
+fn convert_body(body: &Value) -> Option<Value> {
+    let mode = body.get("mode").and_then(|m| m.as_str())?;
+
+    match mode {
+        "raw" => {
+            let raw = body.get("raw").and_then(|r| r.as_str()).unwrap_or("");
+            let language = body
+                .get("options")
+                .and_then(|o| o.get("raw"))
+                .and_then(|r| r.get("language"))
+                .and_then(|l| l.as_str())
+                .unwrap_or("json");
+
+            if language == "json" {
+                if let Ok(example) = serde_json::from_str::<Value>(raw) {
+                    return Some(json!({
+                        "content": {
+                            "application/json": {
+                                "schema": infer_schema(&example),
+                                "example": example
+                            }
+                        }
+                    }));
+                }
+            }
+
+            Some(json!({
+                "content": {
+                    "text/plain": {
+                        "schema": {"type": "string"},
+                        "example": raw
+                    }
+                }
+            }))
+        }
+        "urlencoded" => {
+            let properties = body
+                .get("urlencoded")
+                .and_then(|u| u.as_array())
+                .map(|entries| {
+                    let mut props = Map::new();
+                    for entry in entries {
+                        if let Some(key) = entry.get("key").and_then(|k| k.as_str()) {
+                            props.insert(key.to_string(), json!({"type": "string"}));
+                        }
+                    }
+                    props
+                })
+                .unwrap_or_default();
+
+            Some(json!({
+                "content": {
+                    "application/x-www-form-urlencoded": {
+                        "schema": {"type": "object", "properties": properties}
+                    }
+                }
+            }))
+        }
+        "formdata" => {
+            let properties = body
+                .get("formdata")
+                .and_then(|u| u.as_array())
+                .map(|entries| {
+                    let mut props = Map::new();
+                    for entry in entries {
+                        if let Some(key) = entry.get("key").and_then(|k| k.as_str()) {
+                            let is_file = entry.get("type").and_then(|t| t.as_str()) == Some("file");
+                            let schema = if is_file {
+                                json!({"type": "string", "format": "binary"})
+                            } else {
+                                json!({"type": "string"})
+                            };
+                            props.insert(key.to_string(), schema);
+                        }
+                    }
+                    props
+                })
+                .unwrap_or_default();
+
+            Some(json!({
+                "content": {
+                    "multipart/form-data": {
+                        "schema": {"type": "object", "properties": properties}
+                    }
+                }
+            }))
+        }
+        _ => None,
+    }
+}
+
+fn convert_responses(responses: Option<&Value>) -> Value {
+    let mut result = Map::new();
+
+    if let Some(entries) = responses.and_then(|r| r.as_array()) {
+        for entry in entries {
+            let code = entry
+                .get("code")
+                .and_then(|c| c.as_u64())
+                .unwrap_or(200)
+                .to_string();
+
+            let description = entry
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or("Response")
+                .to_string();
+
+            let mut response = Map::new();
+            response.insert("description".to_string(), json!(description));
+
+            if let Some(raw_body) = entry.get("body").and_then(|b| b.as_str()) {
+                if let Ok(example) = serde_json::from_str::<Value>(raw_body) {
+                    response.insert(
+                        "content".to_string(),
+                        json!({
+                            "application/json": {
+                                "schema": infer_schema(&example),
+                                "example": example
+                            }
+                        }),
+                    );
+                }
+            }
+
+            result.insert(code, Value::Object(response));
+        }
+    }
+
+    if result.is_empty() {
+        result.insert(
+            "200".to_string(),
+            json!({"description": "Successful response"}),
+        );
+    }
+
+    Value::Object(result)
+}
+
+/// Infers a minimal JSON Schema fragment from an example value, recursing through nested
+/// objects and arrays (an array's item schema comes from its first element).
+fn infer_schema(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut properties = Map::new();
+            for (key, val) in map {
+                properties.insert(key.clone(), infer_schema(val));
+            }
+            json!({"type": "object", "properties": properties})
+        }
+        Value::Array(items) => {
+            let item_schema = items.first().map(infer_schema).unwrap_or(json!({}));
+            json!({"type": "array", "items": item_schema})
+        }
+        Value::String(_) => json!({"type": "string"}),
+        Value::Number(n) if n.is_i64() || n.is_u64() => json!({"type": "integer"}),
+        Value::Number(_) => json!({"type": "number"}),
+        Value::Bool(_) => json!({"type": "boolean"}),
+        Value::Null => json!({"type": "null"}),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn resolve_url_raw_with_query_string() {
+        let url = json!("https://api.example.com/users?active=true&limit=10");
+        let (path, path_params, query_params) = resolve_url(&url);
+        assert_eq!(path, "/users");
+        assert!(path_params.is_empty());
+        let names: Vec<&str> = query_params
+            .iter()
+            .filter_map(|p| p.get("name").and_then(|n| n.as_str()))
+            .collect();
+        assert_eq!(names, vec!["active", "limit"]);
+    }
+
+    #[test]
+    fn resolve_url_structured_query() {
+        let url = json!({
+            "raw": "https://api.example.com/users?active=true",
+            "query": [
+                {"key": "active", "value": "true"},
+                {"key": "debug", "value": "1", "disabled": true}
+            ]
+        });
+        let (path, _path_params, query_params) = resolve_url(&url);
+        assert_eq!(path, "/users");
+        let names: Vec<&str> = query_params
+            .iter()
+            .filter_map(|p| p.get("name").and_then(|n| n.as_str()))
+            .collect();
+        assert_eq!(names, vec!["active"]);
+    }
+
+    #[test]
+    fn resolve_url_colon_and_mustache_path_params() {
+        let url = json!("https://{{host}}/users/:userId/orders/{{orderId}}");
+        let (path, path_params, _query_params) = resolve_url(&url);
+        assert_eq!(path, "/users/{userId}/orders/{orderId}");
+        assert_eq!(path_params, vec!["userId", "orderId"]);
+    }
+}
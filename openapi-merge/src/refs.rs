@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Per-source rename bookkeeping: `pointer_map` covers `#/components/<ctype>/<old>` ->
+/// `.../<new>`; `schema_name_map` additionally covers bare schema names used in
+/// `discriminator.mapping`.
+#[derive(Default)]
+pub struct RenameMap {
+    pointer_map: HashMap<String, String>,
+    schema_name_map: HashMap<String, String>,
+}
+
+impl RenameMap {
+    pub fn record_component(&mut self, ctype: &str, old_name: &str, new_name: &str) {
+        if old_name == new_name {
+            return;
+        }
+        self.pointer_map.insert(
+            format!("#/components/{}/{}", ctype, old_name),
+            format!("#/components/{}/{}", ctype, new_name),
+        );
+        if ctype == "schemas" {
+            self.schema_name_map
+                .insert(old_name.to_string(), new_name.to_string());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pointer_map.is_empty()
+    }
+}
+
+/// Rewrites every `$ref` under `value` that points at a renamed component, and patches
+/// `discriminator.mapping` values accordingly. External refs are left untouched.
+pub fn rewrite_refs(value: &mut Value, renames: &RenameMap) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                if let Some(new_ref) = renames.pointer_map.get(reference) {
+                    let new_ref = new_ref.clone();
+                    map.insert("$ref".to_string(), Value::String(new_ref));
+                }
+            }
+
+            if let Some(Value::Object(mapping)) = map
+                .get_mut("discriminator")
+                .and_then(|d| d.get_mut("mapping"))
+            {
+                for (_key, target) in mapping.iter_mut() {
+                    if let Value::String(s) = target {
+                        if let Some(new_ref) = renames.pointer_map.get(s.as_str()) {
+                            *s = new_ref.clone();
+                        } else if let Some(new_name) = renames.schema_name_map.get(s.as_str()) {
+                            *s = new_name.clone();
+                        }
+                    }
+                }
+            }
+
+            for (key, val) in map.iter_mut() {
+                if key == "$ref" {
+                    continue;
+                }
+                rewrite_refs(val, renames);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rewrite_refs(item, renames);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rewrite_refs_follows_renamed_collision() {
+        let mut renames = RenameMap::default();
+        renames.record_component("schemas", "User", "User_2");
+
+        let mut doc = json!({
+            "paths": {
+                "/users": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {"$ref": "#/components/schemas/User"}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "User_2": {
+                        "discriminator": {
+                            "propertyName": "kind",
+                            "mapping": {
+                                "user": "#/components/schemas/User",
+                                "bare": "User"
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        rewrite_refs(&mut doc, &renames);
+
+        assert_eq!(
+            doc["paths"]["/users"]["get"]["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/User_2"
+        );
+        assert_eq!(
+            doc["components"]["schemas"]["User_2"]["discriminator"]["mapping"]["user"],
+            "#/components/schemas/User_2"
+        );
+        assert_eq!(
+            doc["components"]["schemas"]["User_2"]["discriminator"]["mapping"]["bare"],
+            "User_2"
+        );
+    }
+
+    #[test]
+    fn rewrite_refs_leaves_unrenamed_and_external_refs_untouched() {
+        let mut renames = RenameMap::default();
+        renames.record_component("schemas", "User", "User_2");
+
+        let mut doc = json!({
+            "a": {"$ref": "#/components/schemas/Order"},
+            "b": {"$ref": "https://example.com/schemas.json#/Order"}
+        });
+
+        rewrite_refs(&mut doc, &renames);
+
+        assert_eq!(doc["a"]["$ref"], "#/components/schemas/Order");
+        assert_eq!(doc["b"]["$ref"], "https://example.com/schemas.json#/Order");
+    }
+}
@@ -0,0 +1,7 @@
+/// Splits a merged tag of the form "<service> | <original tag>" back into its components.
+pub fn split_service_tag(tag: &str) -> (String, String) {
+    match tag.split_once(" | ") {
+        Some((service, rest)) => (service.to_string(), rest.to_string()),
+        None => ("default".to_string(), tag.to_string()),
+    }
+}
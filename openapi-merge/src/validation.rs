@@ -0,0 +1,184 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde_json::Value;
+
+use crate::format;
+
+/// Vendored verbatim: https://spec.openapis.org/oas/3.0/schema/2021-09-28
+const OPENAPI_3_0_META: &str = include_str!("../assets/openapi-3.0-meta.json");
+/// Vendored verbatim: https://spec.openapis.org/oas/3.1/schema/2022-10-07
+const OPENAPI_3_1_META: &str = include_str!("../assets/openapi-3.1-meta.json");
+
+struct ValidationIssue {
+    pointer: String,
+    keyword: String,
+    message: String,
+}
+
+impl ValidationIssue {
+    fn into_py(self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("pointer", self.pointer)?;
+        dict.set_item("keyword", self.keyword)?;
+        dict.set_item("message", self.message)?;
+        Ok(dict.into_py(py))
+    }
+}
+
+/// Selects the bundled meta-schema matching the document's declared OpenAPI 3.0/3.1 version;
+/// Swagger 2.0 documents and other versions are rejected with an error.
+fn select_meta_schema(schema: &Value) -> Result<&'static str, String> {
+    if let Some(version) = schema.get("openapi").and_then(|v| v.as_str()) {
+        if version.starts_with("3.1") {
+            return Ok(OPENAPI_3_1_META);
+        }
+        if version.starts_with("3.0") {
+            return Ok(OPENAPI_3_0_META);
+        }
+        return Err(format!("Unsupported openapi version: {}", version));
+    }
+
+    if schema.get("swagger").is_some() {
+        return Err("Swagger 2.0 documents are not supported; convert to OpenAPI 3.x first".to_string());
+    }
+
+    Err("Document is missing an `openapi` or `swagger` version field".to_string())
+}
+
+/// Note: the 3.1 meta-schema needs a `jsonschema` crate with draft 2020-12 `$dynamicRef` support.
+fn run_meta_schema_validation(schema: &Value) -> Result<Vec<ValidationIssue>, String> {
+    let meta_schema_json = select_meta_schema(schema)?;
+    let meta_schema: Value = serde_json::from_str(meta_schema_json)
+        .map_err(|e| format!("Bundled meta-schema is invalid JSON: {}", e))?;
+
+    let compiled = jsonschema::JSONSchema::compile(&meta_schema)
+        .map_err(|e| format!("Failed to compile meta-schema: {}", e))?;
+
+    let mut issues = Vec::new();
+    if let Err(errors) = compiled.validate(schema) {
+        for error in errors {
+            let pointer = error.instance_path.to_string();
+            let message = error.to_string();
+            let schema_path = error.schema_path.to_string();
+            let keyword = schema_path
+                .rsplit('/')
+                .next()
+                .filter(|k| !k.is_empty())
+                .unwrap_or("schema")
+                .to_string();
+            issues.push(ValidationIssue {
+                pointer: if pointer.is_empty() {
+                    "/".to_string()
+                } else {
+                    pointer
+                },
+                keyword,
+                message,
+            });
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Collects every internal `#/...` ref (including `#/components/.../Name`) reachable from `root`
+/// with no matching target.
+fn find_dangling_refs(root: &Value) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    walk_refs(root, root, "".to_string(), &mut issues);
+    issues
+}
+
+fn walk_refs(root: &Value, node: &Value, pointer: String, issues: &mut Vec<ValidationIssue>) {
+    match node {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                if reference.starts_with("#/") && !ref_target_exists(root, reference) {
+                    issues.push(ValidationIssue {
+                        pointer: format!("{}/$ref", pointer),
+                        keyword: "$ref".to_string(),
+                        message: format!("Unresolved internal reference: {}", reference),
+                    });
+                }
+            }
+            for (key, val) in map {
+                walk_refs(root, val, format!("{}/{}", pointer, escape_pointer(key)), issues);
+            }
+        }
+        Value::Array(items) => {
+            for (idx, item) in items.iter().enumerate() {
+                walk_refs(root, item, format!("{}/{}", pointer, idx), issues);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn escape_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+fn ref_target_exists(root: &Value, reference: &str) -> bool {
+    let path = reference.trim_start_matches("#/");
+    let mut current = root;
+    for part in path.split('/') {
+        let part = part.replace("~1", "/").replace("~0", "~");
+        match current.get(&part) {
+            Some(next) => current = next,
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Builds a `{valid: false, errors: [...]}` result for input that couldn't be parsed into a document.
+fn format_error_result(py: Python, keyword: &str, message: String) -> PyResult<PyObject> {
+    let result = PyDict::new(py);
+    result.set_item("valid", false)?;
+    let errors = PyList::empty(py);
+    let issue = ValidationIssue {
+        pointer: "/".to_string(),
+        keyword: keyword.to_string(),
+        message,
+    };
+    errors.append(issue.into_py(py)?)?;
+    result.set_item("errors", errors)?;
+    Ok(result.into_py(py))
+}
+
+#[pyfunction]
+pub fn validate_schema_rust(py: Python, schema_json: &str) -> PyResult<PyObject> {
+    let schema: Value = match format::parse_flexible(schema_json) {
+        Ok(v) if v.is_object() => v,
+        Ok(_) => {
+            return format_error_result(
+                py,
+                "type",
+                "Input parsed as JSON5/YAML but is not a document object".to_string(),
+            );
+        }
+        Err(e) => return format_error_result(py, "format", e),
+    };
+
+    let mut all_issues = Vec::new();
+    match run_meta_schema_validation(&schema) {
+        Ok(mut issues) => all_issues.append(&mut issues),
+        Err(message) => all_issues.push(ValidationIssue {
+            pointer: "/".to_string(),
+            keyword: "openapi".to_string(),
+            message,
+        }),
+    }
+
+    all_issues.extend(find_dangling_refs(&schema));
+
+    let result = PyDict::new(py);
+    result.set_item("valid", all_issues.is_empty())?;
+    let errors = PyList::empty(py);
+    for issue in all_issues {
+        errors.append(issue.into_py(py)?)?;
+    }
+    result.set_item("errors", errors)?;
+
+    Ok(result.into_py(py))
+}
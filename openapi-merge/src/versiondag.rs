@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::diff::diff_schemas_rust;
+use crate::fsutil;
+
+/// One node in the content-addressed merge history, keyed by the hash of the merged
+/// document's canonical form.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VersionNode {
+    pub hash: String,
+    pub parent: Option<String>,
+    pub sources: Vec<SourceRef>,
+    pub timestamp: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SourceRef {
+    pub name: String,
+    pub url: String,
+}
+
+fn versions_dir(store_dir: &Path) -> PathBuf {
+    store_dir.join("versions")
+}
+
+fn head_path(store_dir: &Path) -> PathBuf {
+    store_dir.join("HEAD")
+}
+
+fn node_path(store_dir: &Path, hash: &str) -> PathBuf {
+    versions_dir(store_dir).join(format!("{}.json", hash))
+}
+
+fn schema_path(store_dir: &Path, hash: &str) -> PathBuf {
+    versions_dir(store_dir).join(format!("{}.schema.json", hash))
+}
+
+/// Serializes `value` with object keys sorted, so structurally-identical merges hash identically.
+fn canonical_bytes(value: &Value) -> Vec<u8> {
+    fn sort(value: &Value) -> Value {
+        match value {
+            Value::Object(map) => {
+                let sorted: BTreeMap<String, Value> =
+                    map.iter().map(|(k, v)| (k.clone(), sort(v))).collect();
+                serde_json::to_value(sorted).unwrap()
+            }
+            Value::Array(items) => Value::Array(items.iter().map(sort).collect()),
+            other => other.clone(),
+        }
+    }
+    serde_json::to_vec(&sort(value)).unwrap()
+}
+
+fn content_hash(value: &Value) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(canonical_bytes(value));
+    format!("{:x}", hasher.finalize())
+}
+
+fn read_head(store_dir: &Path) -> Option<String> {
+    fs::read_to_string(head_path(store_dir))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn read_node(store_dir: &Path, hash: &str) -> VersionResult<VersionNode> {
+    let content = fs::read_to_string(node_path(store_dir, hash))?;
+    serde_json::from_str(&content).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+type VersionResult<T> = std::io::Result<T>;
+
+/// Records a merge result as a new DAG node (deduplicated against the current HEAD) and
+/// returns the resulting content hash. Node, schema, and HEAD writes share one `store_dir` lock.
+pub fn record_version(
+    store_dir: &Path,
+    merged: &Value,
+    sources: Vec<SourceRef>,
+) -> VersionResult<String> {
+    fs::create_dir_all(versions_dir(store_dir))?;
+    let _lock = fsutil::lock_dir(store_dir)?;
+
+    let hash = content_hash(merged);
+    let parent = read_head(store_dir);
+
+    if parent.as_deref() == Some(hash.as_str()) {
+        return Ok(hash);
+    }
+
+    if node_path(store_dir, &hash).exists() {
+        fsutil::write_atomic(&head_path(store_dir), hash.as_bytes())?;
+        return Ok(hash);
+    }
+
+    let node = VersionNode {
+        hash: hash.clone(),
+        parent,
+        sources,
+        timestamp: Utc::now().to_rfc3339(),
+    };
+
+    fsutil::write_atomic(&node_path(store_dir, &hash), serde_json::to_string(&node).unwrap().as_bytes())?;
+    fsutil::write_atomic(&schema_path(store_dir, &hash), serde_json::to_vec(merged).unwrap().as_slice())?;
+    fsutil::write_atomic(&head_path(store_dir), hash.as_bytes())?;
+
+    Ok(hash)
+}
+
+#[pyfunction]
+pub fn history_rust(store_dir: &str) -> PyResult<String> {
+    let store_dir = Path::new(store_dir);
+    let mut nodes = Vec::new();
+    let mut current = read_head(store_dir);
+
+    while let Some(hash) = current {
+        let node = read_node(store_dir, &hash)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+        current = node.parent.clone();
+        nodes.push(node);
+    }
+
+    Ok(serde_json::to_string(&nodes).unwrap())
+}
+
+#[pyfunction]
+pub fn get_version_rust(store_dir: &str, hash: &str) -> PyResult<String> {
+    let store_dir = Path::new(store_dir);
+    let node = read_node(store_dir, hash)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Unknown version {}: {}", hash, e)))?;
+    let schema_content = fs::read_to_string(schema_path(store_dir, hash))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    let schema: Value = serde_json::from_str(&schema_content)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(serde_json::to_string(&serde_json::json!({
+        "node": node,
+        "schema": schema
+    }))
+    .unwrap())
+}
+
+/// Atomically restores `hash` as `current.json` and moves HEAD to it, under one `store_dir` lock.
+#[pyfunction]
+pub fn rollback_rust(store_dir: &str, hash: &str) -> PyResult<String> {
+    let store_dir = Path::new(store_dir);
+    read_node(store_dir, hash)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Unknown version {}: {}", hash, e)))?;
+
+    let schema_content = fs::read_to_string(schema_path(store_dir, hash))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+
+    let current_path = store_dir.join("current.json");
+
+    let _lock = fsutil::lock_dir(store_dir)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    fsutil::write_atomic(&current_path, schema_content.as_bytes())
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    fsutil::write_atomic(&head_path(store_dir), hash.as_bytes())
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+
+    Ok(current_path.to_string_lossy().to_string())
+}
+
+/// Diffs the merged documents of two recorded versions, reusing the change-feed diff logic.
+#[pyfunction]
+pub fn diff_versions_rust(store_dir: &str, hash_a: &str, hash_b: &str) -> PyResult<String> {
+    let store_dir = Path::new(store_dir);
+    let schema_a = fs::read_to_string(schema_path(store_dir, hash_a))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    let schema_b = fs::read_to_string(schema_path(store_dir, hash_b))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+
+    diff_schemas_rust(&schema_a, &schema_b)
+}